@@ -10,6 +10,8 @@ pub(crate) type TransformationTokenResult<T> = Result<T, TransformTokenStreamErr
 pub enum TransformTokenStreamError {
     #[error("Failed to transform token stream: {0}")]
     TransformError(String, Span),
+    #[error("Failed to transform token stream with {} errors", .0.len())]
+    MultiError(Vec<(String, Span)>),
 }
 
 impl From<ParseTokenStreamError> for TransformTokenStreamError {
@@ -33,19 +35,69 @@ impl From<GenerateTokenStreamError> for TransformTokenStreamError {
 }
 
 impl TransformTokenStreamError {
-    /// Converts the `TransformTokenStreamError` into a compiler error message.
-    /// This function takes a `Span` and returns a `TokenTree` representing the compiler error.
+    /// Flattens `self` into its list of `(message, span)` errors.
+    fn into_errors(self) -> Vec<(String, Span)> {
+        match self {
+            Self::TransformError(message, span) => vec![(message, span)],
+            Self::MultiError(errors) => errors,
+        }
+    }
+
+    /// Combines `self` with `other` into a single `MultiError`, flattening any
+    /// `MultiError`s already held by either side rather than nesting them.
+    pub(crate) fn combine(self, other: Self) -> Self {
+        let mut errors = self.into_errors();
+        errors.extend(other.into_errors());
+        Self::MultiError(errors)
+    }
+
+    /// Pushes `other` onto `self` in place, combining both into a `MultiError`.
     ///
-    /// # Arguments
+    /// This lets callers accumulate every error encountered while walking a
+    /// token stream instead of bailing out on the first one.
+    pub(crate) fn push(&mut self, other: Self) {
+        let current = std::mem::replace(self, Self::MultiError(Vec::new()));
+        *self = current.combine(other);
+    }
+
+    /// Converts the `TransformTokenStreamError` into a compiler error `TokenStream`,
+    /// following the `to_compile_error`/`into_compile_error` convention used by `syn`.
+    /// Emits one spanned `compile_error!` invocation per collected error so that a
+    /// single `cargo build` surfaces every malformed literal at once.
     ///
-    /// * `span` - The span of the source code where the error occurred.
+    /// A lone error expands to a single `compile_error!{ .. }` invocation, which is
+    /// itself a valid expression. Multiple errors are wrapped in one `{ .. }` block
+    /// containing every `compile_error!{ .. }` invocation followed by a trailing
+    /// `loop {}`: a bare sequence of macro invocations would only parse as a single
+    /// expression in statement position, so `let x = sui_literal!(..)` would silently
+    /// keep just the first error and drop the rest. The block form parses as one
+    /// expression everywhere `sui_literal!` can be invoked, and the diverging `loop {}`
+    /// tail lets it type-check against whatever the caller expected.
     ///
     /// # Returns
     ///
-    /// A `TokenTree` representing the compiler error message.
-    pub fn into_compiler_error(self) -> TokenTree {
+    /// A `TokenStream` containing one `compile_error!` invocation per error.
+    pub fn into_compile_error_stream(self) -> TokenStream {
         match self {
-            TransformTokenStreamError::TransformError(message, span) => error(span, &message),
+            Self::TransformError(message, span) => TokenStream::from(error(span, &message)),
+            Self::MultiError(errors) => {
+                let span = errors
+                    .first()
+                    .map_or_else(Span::call_site, |(_, span)| *span);
+
+                let mut inner = TokenStream::new();
+                for (message, span) in errors {
+                    inner.extend(TokenStream::from(error(span, &message)));
+                }
+                inner.extend(TokenStream::from_iter(vec![
+                    TokenTree::Ident(Ident::new("loop", span)),
+                    TokenTree::Group(Group::new(Delimiter::Brace, TokenStream::new())),
+                ]));
+
+                let mut block = Group::new(Delimiter::Brace, inner);
+                block.set_span(span);
+                TokenStream::from(TokenTree::Group(block))
+            }
         }
     }
 }
@@ -16,16 +16,12 @@
 //! - `UNDERSCORE`: Constant character `_` used for suffix parsing.
 //! - `SUI_ADDRESS_BYTE_LENGTH`: Length of bytes for `SuiAddress`.
 //!
-//! ## Enum `TransformInto`
+//! ## Suffix registry
 //!
-//! Enumerates the transformation target types:
-//!
-//! - `SuiAddress`: Indicates transformation into `SuiAddress`.
-//! - `ObjectID`: Indicates transformation into `ObjectID`.
-//!
-//! ## Function `TransformInto::from_str`
-//!
-//! Parses a string slice to determine the transformation target.
+//! `SUFFIX_REGISTRY` maps each recognized `_<suffix>` (currently `address` and
+//! `object`) to a `SuffixDescriptor` describing its expected byte length,
+//! decode mode, and constructing function. New hex-decoded Sui literal types are
+//! added by registering another descriptor rather than editing the transform logic.
 //!
 //! ## Function `compute_str_limbs`
 //!
@@ -73,10 +69,26 @@
 //! use sui_types::base_types::{ObjectID, SuiAddress};
 //! use std::str::FromStr;
 //!
-//! let sui_address = sui_literal!(0x01b0d52321ce82d032430f859c6df081d56d89445db2d624f0_object);
+//! let object_id = sui_literal!(0x01b0d52321ce82d032430f859c6df0c52eb9ce1a337a81d56d89445db2d624f0ff_object);
+//! ```
+//!
+//! This example demonstrates a compile-time failure because the hex body is longer
+//! than 64 nibbles, which cannot fit into the 32-byte array a `_object` suffix expects
+//! (shorthand, zero-padded bodies shorter than 64 nibbles are accepted, but overlong
+//! ones are still rejected).
+//!
+//! ```compile_fail
+//! use sui_literals::sui_literal;
+//! use sui_types::base_types::{ObjectID, SuiAddress};
+//! use std::str::FromStr;
+//!
+//! let pair = sui_literal!((0x01b0d52321ce82d032430f859c6df0c52eb9ce1a337a81d56d89445db2d624f0ff_object, 0x_address));
 //! ```
 //!
-//! The above example also demonstrates a compile-time failure with an invalid suffix `_obct`.
+//! This example demonstrates that every malformed literal in a stream is reported,
+//! not just the first one: the first tuple element is rejected for being too long,
+//! and the second for having no hex nibbles before its suffix, and both
+//! `compile_error!`s are emitted from the same `sui_literal!` invocation.
 //!
 //! # Notes
 //!
@@ -103,48 +115,66 @@ const UNDERSCORE: char = '_';
 const O_X: &str = "0x";
 const SUI_ADDRESS_BYTE_LENGTH: usize = 32;
 
-/// Enumerates the target types for transformation.
-#[derive(Debug)]
-enum TransformInto {
-    SuiAddress,
-    ObjectID,
+/// How a suffix descriptor's literal body is decoded into bytes.
+#[derive(Debug, Clone, Copy)]
+enum DecodeMode {
+    /// The body is a hexadecimal string, as produced by `0x`-prefixed literals.
+    Hex,
 }
 
-impl TransformInto {
-    /// Parses a string slice to determine the transformation target.
-    fn from_str(which: &str, span: Span) -> ParsingResult<Self> {
-        match which {
-            "address" => Ok(Self::SuiAddress),
-            "object" => Ok(Self::ObjectID),
-            _ => Err(ParseTokenStreamError::ParseError(
-                format!("Suffix must be either `address` or `object`, but found `{which}`",),
-                span,
-            )),
-        }
-    }
+/// Describes one registered `_<suffix>` literal suffix: the number of bytes
+/// it decodes to, how its body is decoded, and how to turn the decoded limbs
+/// into the constructing `TokenStream`.
+///
+/// Adding a new hex-decoded Sui literal type is a matter of adding another
+/// descriptor here rather than touching `parse_suffix`/`transform_literal`.
+/// `DecodeMode::Hex` is the only decode mode; a body in another encoding
+/// (e.g. a base58 `_digest` body) also needs a new `DecodeMode` variant and
+/// its arm in `transform_literal`.
+struct SuffixDescriptor {
+    suffix: &'static str,
+    byte_length: usize,
+    decode_mode: DecodeMode,
+    construct: fn(&[u8], Span) -> GenerationTokenResult<TokenStream>,
+}
+
+/// The suffixes `sui_literal!` currently understands.
+const SUFFIX_REGISTRY: &[SuffixDescriptor] = &[
+    SuffixDescriptor {
+        suffix: "address",
+        byte_length: SUI_ADDRESS_BYTE_LENGTH,
+        decode_mode: DecodeMode::Hex,
+        construct: construct_address,
+    },
+    SuffixDescriptor {
+        suffix: "object",
+        byte_length: SUI_ADDRESS_BYTE_LENGTH,
+        decode_mode: DecodeMode::Hex,
+        construct: construct_objectid,
+    },
+];
+
+/// Looks up a registered suffix descriptor by name.
+fn lookup_suffix(suffix: &str) -> Option<&'static SuffixDescriptor> {
+    SUFFIX_REGISTRY.iter().find(|descriptor| descriptor.suffix == suffix)
 }
 
-/// Computes a string representation of limbs for hexadecimal literals.
-fn compute_str_limbs(limbs: &[u8], span: Span) -> GenerationTokenResult<String> {
+/// Computes a string representation of limbs for hexadecimal literals,
+/// left-padded to `byte_length` bytes.
+fn compute_str_limbs(limbs: &[u8], byte_length: usize, span: Span) -> GenerationTokenResult<String> {
     debug_eprintln!("inside `compute_str_limbs`; limbs = {:?}", &limbs);
 
-    if limbs.len() > SUI_ADDRESS_BYTE_LENGTH {
+    if limbs.len() > byte_length {
         return Err(GenerateTokenStreamError::GenerationError(
-            format!(
-                "Expected {} limbs, found {}",
-                SUI_ADDRESS_BYTE_LENGTH,
-                limbs.len()
-            ),
+            format!("Expected {byte_length} limbs, found {}", limbs.len()),
             span,
         ));
     }
 
     let mut limbs_str = String::new();
-    let mut limbs_vec = vec![0; SUI_ADDRESS_BYTE_LENGTH];
+    let mut limbs_vec = vec![0; byte_length];
 
-    for (limb, b) in limbs_vec.iter_mut().zip(limbs) {
-        *limb = *b;
-    }
+    limbs_vec[byte_length - limbs.len()..].copy_from_slice(limbs);
 
     for limb in limbs_vec {
         let _ = write!(&mut limbs_str, "{limb}_u8, ")
@@ -158,7 +188,7 @@ fn compute_str_limbs(limbs: &[u8], span: Span) -> GenerationTokenResult<String>
 
 /// Constructs an `ObjectID` literal from limbs.
 fn construct_objectid(limbs: &[u8], span: Span) -> GenerationTokenResult<TokenStream> {
-    let limbs_str = compute_str_limbs(limbs, span)?;
+    let limbs_str = compute_str_limbs(limbs, SUI_ADDRESS_BYTE_LENGTH, span)?;
     let source = format!(
         "{{
         use ::sui_types as __suitypes;
@@ -176,7 +206,7 @@ fn construct_objectid(limbs: &[u8], span: Span) -> GenerationTokenResult<TokenSt
 
 /// Constructs a `SuiAddress` literal from limbs.
 fn construct_address(limbs: &[u8], span: Span) -> GenerationTokenResult<TokenStream> {
-    let limbs_str = compute_str_limbs(limbs, span)?;
+    let limbs_str = compute_str_limbs(limbs, SUI_ADDRESS_BYTE_LENGTH, span)?;
     let object_id_source = format!("__suitypes::base_types::ObjectID::new([{limbs_str}])");
     let source = format!(
         "{{
@@ -194,8 +224,9 @@ fn construct_address(limbs: &[u8], span: Span) -> GenerationTokenResult<TokenStr
     })
 }
 
-/// Parses the suffix following a literal to determine transformation type and value.
-fn parse_suffix(source: &Literal) -> ParsingResult<(TransformInto, String)> {
+/// Parses the suffix following a literal, returning its registered descriptor
+/// together with the (possibly zero-padded) literal body.
+fn parse_suffix(source: &Literal) -> ParsingResult<(&'static SuffixDescriptor, String)> {
     let span = source.span();
     let source = source.to_string();
 
@@ -214,88 +245,145 @@ fn parse_suffix(source: &Literal) -> ParsingResult<(TransformInto, String)> {
     debug_eprintln!("inside `parse_suffix`; `value` = {value}");
     debug_eprintln!("inside `parse_suffix`; `suffix` = {suffix}");
 
-    if value.len() != 64 {
+    let descriptor = lookup_suffix(suffix).ok_or_else(|| {
+        let known_suffixes = SUFFIX_REGISTRY
+            .iter()
+            .map(|descriptor| descriptor.suffix)
+            .collect::<Vec<_>>()
+            .join("`, `");
+        ParseTokenStreamError::ParseError(
+            format!("Suffix must be one of `{known_suffixes}`, but found `{suffix}`"),
+            span,
+        )
+    })?;
+
+    if value.is_empty() {
         return Err(ParseTokenStreamError::ParseError(
-            "the address cannot be converted into a byte array of size 32".to_string(),
+            "expected at least one hex nibble after `0x`".to_string(),
             span,
         ));
     }
 
-    let address_or_object = TransformInto::from_str(suffix, span)?;
+    if value.len() > descriptor.byte_length * 2 {
+        return Err(ParseTokenStreamError::ParseError(
+            format!(
+                "the address cannot be converted into a byte array of size {}",
+                descriptor.byte_length
+            ),
+            span,
+        ));
+    }
+
+    // Sui renders shorthand addresses like `0x2`/`0x5` with the leading zero
+    // nibbles elided; pad back to an even-length hex body so `hex::decode`
+    // accepts it, leaving `compute_str_limbs` to left-pad the remaining bytes.
+    let value = if value.len() % 2 == 1 {
+        format!("0{value}")
+    } else {
+        value.to_string()
+    };
 
-    Ok((address_or_object, value.into()))
+    Ok((descriptor, value))
+}
+
+/// Returns `true` if `literal` carries a suffix registered in [`SUFFIX_REGISTRY`].
+///
+/// Literals without a registered suffix (ordinary numeric or string literals)
+/// are left untouched rather than rewritten, so the macro composes with
+/// arbitrary surrounding Rust code instead of only accepting a bare literal.
+fn literal_has_recognized_suffix(literal: &Literal) -> bool {
+    let source = literal.to_string();
+    source
+        .rfind(UNDERSCORE)
+        .is_some_and(|index| lookup_suffix(&source[index + 1..]).is_some())
 }
 
 /// Transforms a literal into a token stream based on its suffix.
 fn transform_literal(source: &Literal) -> TransformationTokenResult<TokenStream> {
-    let (address_or_object, value) = parse_suffix(source)?;
+    let (descriptor, value) = parse_suffix(source)?;
 
     debug_eprintln!("inside `transform_literal`; `value` = {value}");
-    let limbs = hex::decode(&value).map_err(|e| {
-        ParseTokenStreamError::ParseError(
-            format!("Unable to decode `{}` into hexadecimal; error: {e}", &value),
-            source.span(),
-        )
-    })?;
-
-    match address_or_object {
-        TransformInto::ObjectID => Ok(construct_objectid(&limbs, source.span())?),
-        TransformInto::SuiAddress => Ok(construct_address(&limbs, source.span())?),
-    }
+    let limbs = match descriptor.decode_mode {
+        DecodeMode::Hex => hex::decode(&value).map_err(|e| {
+            ParseTokenStreamError::ParseError(
+                format!("Unable to decode `{}` into hexadecimal; error: {e}", &value),
+                source.span(),
+            )
+        })?,
+    };
+
+    Ok((descriptor.construct)(&limbs, source.span())?)
 }
 
-/// Iteratively transforms all literals within a token tree.
+/// Transforms a single `TokenTree`, recursing into `Group`s and rewriting
+/// `Literal`s that carry a recognized suffix.
+///
+/// `Ident`s and `Punct`s are forwarded unchanged, and so are `Literal`s
+/// without a recognized `_address`/`_object` suffix, so that `sui_literal!`
+/// can wrap arbitrary statements and expressions instead of a bare literal.
 fn transform_tree(tree: TokenTree) -> TransformationTokenResult<TokenTree> {
-    let mut stack = vec![tree];
-    let mut result_stack = Vec::new();
-
-    while let Some(current_tree) = stack.pop() {
-        match current_tree {
-            TokenTree::Group(group) => {
-                let delimiter = group.delimiter();
-                let span = group.span();
-                let transformed_stream = transform_stream_hash(group.stream())?;
-                let mut transformed_group = Group::new(delimiter, transformed_stream);
-                transformed_group.set_span(span);
-                result_stack.push(TokenTree::Group(transformed_group));
-            }
-            TokenTree::Literal(literal) => {
-                let span = literal.span();
-                let transformed_tree = match transform_literal(&literal) {
-                    Ok(stream) => {
-                        let mut group = Group::new(Delimiter::None, stream);
-                        group.set_span(span);
-                        TokenTree::Group(group)
-                    }
-                    Err(message) => {
-                        return Err(message);
-                    }
-                };
-                transformed_tree.set_span(span);
-                result_stack.push(transformed_tree);
-            }
-            other => {
-                return Err(TransformTokenStreamError::TransformError(
-                    "Only `TokenTree::Group` and `TokenTree::Literal` are allowed in the `TokenStream`"
-                        .to_string(),
-                    other.span(),
-                ));
+    match tree {
+        TokenTree::Group(group) => {
+            let delimiter = group.delimiter();
+            let span = group.span();
+            let transformed_stream = transform_stream_hash(group.stream())?;
+            let mut transformed_group = Group::new(delimiter, transformed_stream);
+            transformed_group.set_span(span);
+            Ok(TokenTree::Group(transformed_group))
+        }
+        TokenTree::Literal(literal) => {
+            if !literal_has_recognized_suffix(&literal) {
+                return Ok(TokenTree::Literal(literal));
             }
+
+            let span = literal.span();
+            let stream = transform_literal(&literal)?;
+            let mut group = Group::new(Delimiter::None, stream);
+            group.set_span(span);
+            Ok(TokenTree::Group(group))
         }
+        other => Ok(other),
     }
-
-    Ok(result_stack.pop().unwrap())
 }
 
-/// Iterates over a `TokenStream` and transforms all `TokenTree`s.
-pub fn transform_stream_hash(stream: TokenStream) -> TransformationTokenResult<TokenStream> {
+/// Rewrites every `_address`/`_object`-suffixed `Literal` in `stream` into the
+/// corresponding Sui type constructor, recursing into nested `Group`s and
+/// forwarding every other token unchanged.
+///
+/// Tokens are visited in their original left-to-right order, and every
+/// token is visited even after an error is encountered, so a single
+/// `TokenStream` with several malformed literals reports all of them at
+/// once instead of bailing out on the first one.
+///
+/// This is the crate-internal entry point used by the `sui_literal`
+/// proc-macro itself. It is not part of this crate's public API: `sui-literals`
+/// is a `proc-macro = true` crate, which rustc forbids from exporting any
+/// plain item from its root, so this cannot be re-exported for downstream
+/// proc-macros to call directly without first splitting it into a separate,
+/// non-proc-macro core crate.
+pub(crate) fn transform_stream_hash(
+    stream: TokenStream,
+) -> Result<TokenStream, TransformTokenStreamError> {
     let mut result = TokenStream::new();
-    let mut stack: Vec<TokenTree> = stream.into_iter().collect();
-
-    while let Some(tree) = stack.pop() {
-        result.extend(TokenStream::from(transform_tree(tree)?));
+    let mut errors: Option<TransformTokenStreamError> = None;
+
+    for tree in stream {
+        match transform_tree(tree) {
+            Ok(transformed) => {
+                if errors.is_none() {
+                    result.extend(TokenStream::from(transformed));
+                }
+            }
+            Err(err) => match &mut errors {
+                Some(accumulated) => accumulated.push(err),
+                None => errors = Some(err),
+            },
+        }
     }
 
-    Ok(result)
+    match errors {
+        Some(err) => Err(err),
+        None => Ok(result),
+    }
 }
 
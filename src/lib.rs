@@ -4,10 +4,17 @@ mod hex;
 use hex::transform_stream_hash;
 use proc_macro::TokenStream;
 
+/// Rewrites every `_address`/`_object`-suffixed literal in `input` into the
+/// corresponding Sui type constructor.
+///
+/// This is a thin shim over [`transform_stream_hash`]. `proc-macro = true`
+/// crates cannot re-export plain items from their root (only
+/// `#[proc_macro]`/`#[proc_macro_derive]`/`#[proc_macro_attribute]`
+/// functions), so `transform_stream_hash` stays crate-internal for now; a
+/// downstream proc-macro that wants to embed Sui-literal transformation
+/// would need it split out into a separate, non-proc-macro core crate that
+/// this one depends on.
 #[proc_macro]
 pub fn sui_literal(input: TokenStream) -> TokenStream {
-    match transform_stream_hash(input) {
-        Err(err) => err.into_compiler_error().into(),
-        Ok(ts) => ts,
-    }
+    transform_stream_hash(input).unwrap_or_else(|e| e.into_compile_error_stream())
 }
@@ -30,4 +30,45 @@ mod tests {
         let expected_sui_address = SuiAddress::from(expected_object_id);
         assert_eq!(expected_sui_address, sui_address);
     }
+
+    #[test]
+    fn test_shorthand_address() {
+        let object_id = sui_literal!(0x2_object);
+        let expected_object_id = ObjectID::from_str(
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+        )
+        .unwrap();
+        assert_eq!(expected_object_id, object_id);
+    }
+
+    #[test]
+    fn test_pass_through_let_statement() {
+        // `sui_literal!` expands a `let` pass-through to a `let` statement, so
+        // the macro must be invoked in statement position (ending in `;`)
+        // rather than assigned directly via `let a = sui_literal!(...)`, which
+        // would require the expansion to be a single expression.
+        let expected_object_id = ObjectID::from_str(
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+        )
+        .unwrap();
+
+        sui_literal!(let a = 0x2_object;);
+        assert_eq!(expected_object_id, a);
+    }
+
+    #[test]
+    fn test_pass_through_preserves_order() {
+        // `sui_literal!` must visit tokens left-to-right, so the two literals
+        // below construct `(0x2, 0x3)` and not `(0x3, 0x2)`.
+        let pair = sui_literal!((0x2_object, 0x3_object));
+        let expected_first = ObjectID::from_str(
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+        )
+        .unwrap();
+        let expected_second = ObjectID::from_str(
+            "0x0000000000000000000000000000000000000000000000000000000000000003",
+        )
+        .unwrap();
+        assert_eq!((expected_first, expected_second), pair);
+    }
 }
@@ -0,0 +1,16 @@
+//! Asserts the exact compiler output of failing `sui_literal!` invocations,
+//! which a `compile_fail` doctest cannot: a doctest only proves *some* error
+//! occurred, not that every accumulated error was reported. Requires a
+//! `trybuild` dev-dependency.
+//!
+//! `tests/ui/multi_error_let_position.rs` has two malformed literals inside a
+//! `let`-bound `sui_literal!` call; its `.stderr` fixture must show both
+//! `compile_error!` messages. Regenerate the fixture with
+//! `TRYBUILD=overwrite cargo test --test trybuild` after any change to the
+//! error messages or to this file.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/multi_error_let_position.rs");
+}
@@ -0,0 +1,13 @@
+// Regression test for the bug where `into_compile_error_stream` only
+// surfaced the first of several accumulated errors when `sui_literal!`
+// is invoked in expression position (e.g. assigned via `let`), because a
+// bare sequence of `compile_error!` invocations only parses as one
+// expression in statement position.
+use sui_literals::sui_literal;
+
+fn main() {
+    let _pair = sui_literal!((
+        0x01b0d52321ce82d032430f859c6df0c52eb9ce1a337a81d56d89445db2d624f0ff_object,
+        0x_address
+    ));
+}